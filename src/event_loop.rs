@@ -78,6 +78,22 @@ impl EventLoopConfig {
         self.timer_capacity = cap;
         self
     }
+
+    // Rejects settings the event loop cannot run with, so they're reported
+    // when the loop is created rather than as a panic or a stall later on.
+    fn validate(&self) -> io::Result<()> {
+        if self.messages_per_tick == 0 {
+            Err(invalid_config("messages per tick must be non-zero"))
+        } else if self.timer_tick_ms == 0 {
+            Err(invalid_config("timer tick must be non-zero"))
+        } else {
+            Ok(())
+        }
+    }
+}
+
+fn invalid_config(msg: &str) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidInput, msg)
 }
 
 /// Single threaded IO event loop.
@@ -101,7 +117,13 @@ impl<H: Handler> EventLoop<H> {
         EventLoop::configured(EventLoopConfig::new())
     }
 
+    /// Initializes a new event loop using the given configuration.
+    ///
+    /// Returns an `InvalidInput` error if the number of messages per tick or
+    /// the timer tick is zero.
     pub fn configured(config: EventLoopConfig) -> io::Result<EventLoop<H>> {
+        try!(config.validate());
+
         // Create the IO poller
         let mut poll = try!(Poll::new());
 
@@ -220,6 +242,18 @@ impl<H: Handler> EventLoop<H> {
         self.timer.clear(timeout)
     }
 
+    /// Returns the number of milliseconds until the soonest pending timeout
+    /// is due, or `None` if there are no pending timeouts.
+    ///
+    /// This is useful when the event loop is driven by
+    /// [#run_once](#method.run_once) from another scheduler: sleeping for at
+    /// most this long before the next call ensures timeouts fire on time.
+    /// Timeouts are rounded up to the timer tick they fire on, so this is the
+    /// time until that tick.
+    pub fn next_timeout_ms(&self) -> Option<u64> {
+        self.timer.next_timeout_in_ms()
+    }
+
     /// Tells the event loop to exit after it is done handling all events in the
     /// current iteration.
     pub fn shutdown(&mut self) {
@@ -233,16 +267,23 @@ impl<H: Handler> EventLoop<H> {
     }
 
     /// Registers an IO handle with the event loop.
+    ///
+    /// `Token(usize::MAX)` is reserved for the event loop's own use, and
+    /// attempting to register a handle with it fails with `InvalidInput`.
     pub fn register<E: ?Sized>(&mut self, io: &E, token: Token, interest: EventSet, opt: PollOpt) -> io::Result<()>
         where E: Evented
     {
+        try!(validate_token(token));
         self.poll.register(io, token, interest, opt)
     }
 
     /// Re-Registers an IO handle with the event loop.
+    ///
+    /// As with [#register](#method.register), the reserved token is rejected.
     pub fn reregister<E: ?Sized>(&mut self, io: &E, token: Token, interest: EventSet, opt: PollOpt) -> io::Result<()>
         where E: Evented
     {
+        try!(validate_token(token));
         self.poll.reregister(io, token, interest, opt)
     }
 
@@ -303,6 +344,11 @@ impl<H: Handler> EventLoop<H> {
         self.io_process(handler, events);
         self.notify(handler, messages);
         self.timer_process(handler);
+
+        if events == 0 && messages == 0 {
+            handler.idle(self);
+        }
+
         handler.tick(self);
         Ok(())
     }
@@ -377,6 +423,15 @@ impl<H: Handler> EventLoop<H> {
     }
 }
 
+fn validate_token(token: Token) -> io::Result<()> {
+    if token == NOTIFY {
+        Err(io::Error::new(io::ErrorKind::InvalidInput,
+                           "token is reserved for use by the event loop"))
+    } else {
+        Ok(())
+    }
+}
+
 unsafe impl<H: Handler> Sync for EventLoop<H> { }
 
 impl <H: Handler> Drop for EventLoop<H> {
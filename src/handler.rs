@@ -30,6 +30,19 @@ pub trait Handler: Sized {
     fn interrupted(&mut self, event_loop: &mut EventLoop<Self>) {
     }
 
+    /// Invoked when a tick of the event loop received no I/O events and no
+    /// messages, e.g. because the poll timed out.
+    ///
+    /// This is a good place for periodic cleanup such as reaping idle
+    /// connections. It is invoked at most once per tick, so when the event
+    /// loop is otherwise idle it runs roughly every
+    /// `min(timer_tick_ms, io_poll_timeout_ms)` milliseconds (see
+    /// [EventLoopConfig](struct.EventLoopConfig.html)), since the poll wakes up
+    /// for every timer tick even when no timeouts are pending. The event loop
+    /// is blocked while this runs, so it should return quickly.
+    fn idle(&mut self, event_loop: &mut EventLoop<Self>) {
+    }
+
     /// Invoked at the end of an event loop tick.
     fn tick(&mut self, event_loop: &mut EventLoop<Self>) {
     }
@@ -157,7 +157,8 @@ pub use notify::{
     NotifyError,
 };
 pub use poll::{
-    Poll
+    Poll,
+    PollPhase,
 };
 pub use timer::{
     Timeout,
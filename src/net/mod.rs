@@ -20,6 +20,16 @@ pub enum IpAddr {
 pub use std::net::Ipv4Addr;
 pub use std::net::Ipv6Addr;
 
+// Returns the address in network byte order, which is how an `in_addr` is
+// laid out on every platform.
+pub fn in_addr(addr: &Ipv4Addr) -> u32 {
+    let o = addr.octets();
+    (((o[0] as u32) << 24) |
+     ((o[1] as u32) << 16) |
+     ((o[2] as u32) <<  8) |
+     ((o[3] as u32) <<  0)).to_be()
+}
+
 impl FromStr for IpAddr {
     type Err = AddrParseError;
 
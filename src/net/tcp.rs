@@ -66,6 +66,27 @@ impl TcpStream {
         })
     }
 
+    /// Creates a new `TcpStream` from an already connected
+    /// `std::net::TcpStream`.
+    ///
+    /// This allows configuring and connecting a socket with the standard
+    /// library (or `net2`) before handing it to mio. Ownership of the stream
+    /// is transferred to the returned `TcpStream`.
+    ///
+    /// The platform specific behavior of this function looks like:
+    ///
+    /// * On Unix, the socket is placed into nonblocking mode.
+    ///
+    /// * On Windows, the socket is used as-is for overlapped I/O once it is
+    ///   registered with an event loop. Sockets created by the standard library
+    ///   are always created with `WSA_FLAG_OVERLAPPED`, so this is safe for
+    ///   any `std::net::TcpStream`.
+    pub fn from_std(stream: net::TcpStream) -> io::Result<TcpStream> {
+        Ok(TcpStream {
+            sys: try!(sys::TcpStream::from_stream(stream)),
+        })
+    }
+
     pub fn peer_addr(&self) -> io::Result<SocketAddr> {
         self.sys.peer_addr()
     }
@@ -85,9 +106,67 @@ impl TcpStream {
         self.sys.set_nodelay(nodelay)
     }
 
+    /// Sets the TCP keepalive time for this socket, or disables keepalive
+    /// with `None`.
+    ///
+    /// The value is the idle time before the first probe is sent. On Windows
+    /// it is applied with `SIO_KEEPALIVE_VALS`, which also uses it as the
+    /// interval between probes; the number of probes is fixed by the system.
     pub fn set_keepalive(&self, seconds: Option<u32>) -> io::Result<()> {
         self.sys.set_keepalive(seconds)
     }
+
+    /// Returns the TCP keepalive time for this socket, or `None` if keepalive
+    /// is disabled.
+    ///
+    /// Windows can only report the interval between probes, so there this is
+    /// the idle time as long as keepalive was configured with `set_keepalive`.
+    /// After `set_keepalive_with_interval` it returns the interval instead.
+    pub fn keepalive(&self) -> io::Result<Option<u32>> {
+        self.sys.keepalive()
+    }
+
+    /// Enables TCP keepalive with separate idle time and probe interval, both
+    /// in seconds.
+    ///
+    /// `idle` is how long the connection must be idle before the first probe
+    /// is sent, and `interval` is the time between unanswered probes. On Unix
+    /// the interval is set with `TCP_KEEPINTVL`. On Windows both values are
+    /// applied with a single `SIO_KEEPALIVE_VALS` ioctl, and the number of
+    /// probes cannot be changed: it is 10 on Windows Vista and later, and 5 on
+    /// older versions.
+    pub fn set_keepalive_with_interval(&self, idle: u32, interval: u32)
+                                       -> io::Result<()> {
+        self.sys.set_keepalive_with_interval(idle, interval)
+    }
+
+    /// Sets the `SO_LINGER` option on this socket.
+    ///
+    /// With `Some(n)`, closing the socket waits up to `n` seconds for unsent
+    /// data to be delivered, and `Some(0)` makes closing send a reset (an
+    /// abortive close) and discard any unsent data. With `None` the socket is
+    /// closed gracefully in the background.
+    ///
+    /// Mio closes the socket when the `TcpStream` is dropped, so these semantics
+    /// apply at that point. On Windows an overlapped write still in progress
+    /// when the stream is dropped is cancelled, so the linger setting only
+    /// covers data the kernel has already accepted into its send buffer.
+    /// A `shutdown` is not affected by this option.
+    ///
+    /// With `Some(n)` for a non-zero `n`, dropping the stream can block the
+    /// dropping thread, usually the event loop's, for up to `n` seconds while
+    /// unsent data drains. This happens even though the socket is
+    /// non-blocking, since Linux lingers in `close` regardless and Windows
+    /// sockets here are in blocking mode. On Windows the timeout is limited to
+    /// 65535 seconds.
+    pub fn set_linger(&self, seconds: Option<u32>) -> io::Result<()> {
+        self.sys.set_linger(seconds)
+    }
+
+    /// Returns the value of the `SO_LINGER` option on this socket.
+    pub fn linger(&self) -> io::Result<Option<u32>> {
+        self.sys.linger()
+    }
 }
 
 fn inaddr_any(other: &SocketAddr) -> SocketAddr {
@@ -116,6 +195,14 @@ impl Write for TcpStream {
         self.sys.write(buf)
     }
 
+    /// Flushes this stream.
+    ///
+    /// On Unix writes are not buffered, so this always succeeds immediately. On
+    /// Windows writes are completed asynchronously, so this returns
+    /// `WouldBlock` until all previously written data has been sent (or
+    /// returns the error if sending failed). In both cases a successful flush
+    /// only means the data was handed to the OS, not that the peer has
+    /// received it.
     fn flush(&mut self) -> io::Result<()> {
         self.sys.flush()
     }
@@ -197,12 +284,32 @@ impl TcpListener {
         sys::TcpListener::new(listener, addr).map(|s| TcpListener { sys: s })
     }
 
+    /// Creates a new `TcpListener` from an instance of a bound and listening
+    /// `std::net::TcpListener`.
+    ///
+    /// This is the same as `from_listener` except that the address is looked
+    /// up from the listener itself.
+    pub fn from_std(listener: net::TcpListener) -> io::Result<TcpListener> {
+        let addr = try!(listener.local_addr());
+        TcpListener::from_listener(listener, &addr)
+    }
+
     /// Accepts a new `TcpStream`.
     ///
     /// Returns a `Ok(None)` when the socket `WOULDBLOCK`, this means the stream
     /// will be ready at a later point.
     pub fn accept(&self) -> io::Result<Option<TcpStream>> {
-        self.sys.accept().map(|o| o.map(|s| TcpStream { sys: s }))
+        self.sys.accept().map(|o| o.map(|(s, _)| TcpStream { sys: s }))
+    }
+
+    /// Returns an iterator over the connections that are ready to be
+    /// accepted.
+    ///
+    /// Each item is the accepted stream along with its peer address. The
+    /// iterator returns `None` once `accept` would block, so it can be
+    /// drained from a readable event and then used again after the next one.
+    pub fn incoming(&self) -> Incoming {
+        Incoming { listener: self }
     }
 
     pub fn local_addr(&self) -> io::Result<SocketAddr> {
@@ -214,6 +321,26 @@ impl TcpListener {
     }
 }
 
+/// An iterator over the connections ready to be accepted on a `TcpListener`.
+///
+/// Created by `TcpListener::incoming`.
+#[derive(Debug)]
+pub struct Incoming<'a> {
+    listener: &'a TcpListener,
+}
+
+impl<'a> Iterator for Incoming<'a> {
+    type Item = io::Result<(TcpStream, SocketAddr)>;
+
+    fn next(&mut self) -> Option<io::Result<(TcpStream, SocketAddr)>> {
+        match self.listener.sys.accept() {
+            Ok(Some((s, addr))) => Some(Ok((TcpStream { sys: s }, addr))),
+            Ok(None) => None,
+            Err(e) => Some(Err(e)),
+        }
+    }
+}
+
 impl Evented for TcpListener {
     fn register(&self, selector: &mut Selector, token: Token,
                 interest: EventSet, opts: PollOpt) -> io::Result<()> {
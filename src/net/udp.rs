@@ -1,6 +1,6 @@
 use {io, sys, Evented, EventSet, IpAddr, PollOpt, Selector, Token};
 use bytes::{Buf, MutBuf};
-use std::net::SocketAddr;
+use std::net::{Ipv4Addr, SocketAddr};
 
 #[derive(Debug)]
 pub struct UdpSocket {
@@ -58,6 +58,23 @@ impl UdpSocket {
         self.sys.set_broadcast(on)
     }
 
+    /// Returns the value of the `SO_BROADCAST` option on this socket.
+    pub fn broadcast(&self) -> io::Result<bool> {
+        self.sys.broadcast()
+    }
+
+    /// Sets the local interface, by address, used to send outgoing IPv4
+    /// multicast datagrams.
+    pub fn set_multicast_if_v4(&self, interface: &Ipv4Addr) -> io::Result<()> {
+        self.sys.set_multicast_if_v4(interface)
+    }
+
+    /// Sets the local interface, by index, used to send outgoing IPv6
+    /// multicast datagrams. An index of 0 selects the default interface.
+    pub fn set_multicast_if_v6(&self, interface: u32) -> io::Result<()> {
+        self.sys.set_multicast_if_v6(interface)
+    }
+
     pub fn set_multicast_loop(&self, on: bool) -> io::Result<()> {
         self.sys.set_multicast_loop(on)
     }
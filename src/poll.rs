@@ -7,6 +7,18 @@ pub use sys::{Events};
 pub struct Poll {
     selector: sys::Selector,
     events: sys::Events,
+    hook: Option<Box<Fn(PollPhase) + Send + Sync>>,
+}
+
+/// The stage of a call to `Poll::poll` reported to a hook installed with
+/// `Poll::set_poll_hook`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum PollPhase {
+    /// The poller is about to wait for at most `timeout_ms` milliseconds.
+    Enter { timeout_ms: usize },
+    /// The poller finished waiting and received `events` events. This is also
+    /// reported, with zero events, if the wait failed.
+    Exit { events: usize },
 }
 
 impl Poll {
@@ -14,9 +26,39 @@ impl Poll {
         Ok(Poll {
             selector: try!(sys::Selector::new()),
             events: sys::Events::new(),
+            hook: None,
+        })
+    }
+
+    /// Creates a new `Poll` which receives at most `capacity` events per call
+    /// to `poll`.
+    ///
+    /// The capacity is a hard bound for each call; any further events stay
+    /// queued until the next call. The capacity must be non-zero.
+    pub fn with_capacity(capacity: usize) -> io::Result<Poll> {
+        if capacity == 0 {
+            return Err(io::Error::new(io::ErrorKind::InvalidInput,
+                                      "events capacity must be non-zero"));
+        }
+
+        Ok(Poll {
+            selector: try!(sys::Selector::new()),
+            events: sys::Events::with_capacity(capacity),
+            hook: None,
         })
     }
 
+    /// Returns the maximum number of events a single call to `poll` returns.
+    pub fn capacity(&self) -> usize {
+        self.events.capacity()
+    }
+
+    /// Grows the maximum number of events returned by a single call to `poll`
+    /// by at least `additional`.
+    pub fn reserve(&mut self, additional: usize) {
+        self.events.reserve(additional);
+    }
+
     pub fn register<E: ?Sized>(&mut self, io: &E, token: Token, interest: EventSet, opts: PollOpt) -> io::Result<()>
         where E: Evented
     {
@@ -50,9 +92,34 @@ impl Poll {
         Ok(())
     }
 
+    /// Installs a hook which is invoked on entry to and exit from every call
+    /// to `poll`, replacing any previously installed hook.
+    ///
+    /// This is intended for tracing and scheduler accounting. The hook is
+    /// invoked on the polling thread outside of the selector, so it may call
+    /// back into other mio types without deadlocking.
+    pub fn set_poll_hook(&mut self, hook: Box<Fn(PollPhase) + Send + Sync>) {
+        self.hook = Some(hook);
+    }
+
+    /// Removes the hook installed with `set_poll_hook`, if any.
+    pub fn clear_poll_hook(&mut self) {
+        self.hook = None;
+    }
+
     pub fn poll(&mut self, timeout_ms: usize) -> io::Result<usize> {
-        try!(self.selector.select(&mut self.events, timeout_ms));
-        Ok(self.events.len())
+        if let Some(ref hook) = self.hook {
+            hook(PollPhase::Enter { timeout_ms: timeout_ms });
+        }
+
+        let res = self.selector.select(&mut self.events, timeout_ms)
+                               .map(|_| self.events.len());
+
+        if let Some(ref hook) = self.hook {
+            hook(PollPhase::Exit { events: *res.as_ref().unwrap_or(&0) });
+        }
+
+        res
     }
 
     pub fn event(&self, idx: usize) -> IoEvent {
@@ -65,4 +132,3 @@ impl fmt::Debug for Poll {
         write!(fmt, "Poll")
     }
 }
-
@@ -120,8 +120,12 @@ pub struct Events {
 
 impl Events {
     pub fn new() -> Events {
+        Events::with_capacity(1024)
+    }
+
+    pub fn with_capacity(cap: usize) -> Events {
         Events {
-            events: Vec::with_capacity(1024),
+            events: Vec::with_capacity(cap),
         }
     }
 
@@ -130,6 +134,17 @@ impl Events {
         self.events.len()
     }
 
+    #[inline]
+    pub fn capacity(&self) -> usize {
+        self.events.capacity()
+    }
+
+    pub fn reserve(&mut self, additional: usize) {
+        let len = self.events.len();
+        let cap = self.events.capacity();
+        self.events.reserve(cap - len + additional);
+    }
+
     #[inline]
     pub fn get(&self, idx: usize) -> IoEvent {
         let epoll = self.events[idx].events;
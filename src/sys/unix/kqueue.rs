@@ -106,10 +106,14 @@ pub struct Events {
 
 impl Events {
     pub fn new() -> Events {
+        Events::with_capacity(1024)
+    }
+
+    pub fn with_capacity(cap: usize) -> Events {
         Events {
-            sys_events: Vec::with_capacity(1024),
-            events: Vec::with_capacity(1024),
-            event_map: HashMap::with_capacity(1024)
+            sys_events: Vec::with_capacity(cap),
+            events: Vec::with_capacity(cap),
+            event_map: HashMap::with_capacity(cap)
         }
     }
 
@@ -118,6 +122,27 @@ impl Events {
         self.events.len()
     }
 
+    /// The number of kernel events that can be received by one call to
+    /// `select`. Coalescing means `len` never exceeds this.
+    #[inline]
+    pub fn capacity(&self) -> usize {
+        self.sys_events.capacity()
+    }
+
+    pub fn reserve(&mut self, additional: usize) {
+        let len = self.sys_events.len();
+        let cap = self.sys_events.capacity();
+        self.sys_events.reserve(cap - len + additional);
+
+        // The coalesced events must be able to hold as many events as the
+        // kernel can return, whatever their own lengths are.
+        let cap = self.sys_events.capacity();
+        let len = self.events.len();
+        self.events.reserve(cap - len);
+        let len = self.event_map.len();
+        self.event_map.reserve(cap - len);
+    }
+
     pub fn get(&self, idx: usize) -> IoEvent {
         self.events[idx]
     }
@@ -1,5 +1,7 @@
 use {io};
+use libc;
 use sys::unix::{nix, Io};
+use std::mem;
 use std::net::SocketAddr;
 use std::os::unix::io::{AsRawFd, RawFd};
 pub use net::tcp::Shutdown;
@@ -67,6 +69,37 @@ pub fn getsockname(io: &Io) -> io::Result<nix::SockAddr> {
         .map_err(super::from_nix_error)
 }
 
+// For options that nix doesn't provide
+pub fn setsockopt<T>(fd: RawFd, level: libc::c_int, name: libc::c_int,
+                     val: &T) -> io::Result<()> {
+    let ret = unsafe {
+        libc::setsockopt(fd, level, name,
+                         val as *const T as *const libc::c_void,
+                         mem::size_of::<T>() as libc::socklen_t)
+    };
+    if ret == 0 {
+        Ok(())
+    } else {
+        Err(io::Error::last_os_error())
+    }
+}
+
+pub fn getsockopt<T: Copy>(fd: RawFd, level: libc::c_int,
+                           name: libc::c_int) -> io::Result<T> {
+    unsafe {
+        let mut val: T = mem::zeroed();
+        let mut len = mem::size_of::<T>() as libc::socklen_t;
+        let ret = libc::getsockopt(fd, level, name,
+                                   &mut val as *mut T as *mut libc::c_void,
+                                   &mut len);
+        if ret == 0 {
+            Ok(val)
+        } else {
+            Err(io::Error::last_os_error())
+        }
+    }
+}
+
 #[inline]
 pub fn dup(io: &Io) -> io::Result<Io> {
     nix::dup(io.as_raw_fd())
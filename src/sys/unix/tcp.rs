@@ -37,6 +37,11 @@ impl TcpStream {
         Ok(TcpStream { inner: stream })
     }
 
+    pub fn from_stream(stream: net::TcpStream) -> io::Result<TcpStream> {
+        try!(set_nonblock(&stream));
+        Ok(TcpStream { inner: stream })
+    }
+
     pub fn peer_addr(&self) -> io::Result<SocketAddr> {
         self.inner.peer_addr()
     }
@@ -60,6 +65,76 @@ impl TcpStream {
     pub fn set_keepalive(&self, seconds: Option<u32>) -> io::Result<()> {
         self.inner.set_keepalive_ms(seconds.map(|s| s * 1000))
     }
+
+    pub fn keepalive(&self) -> io::Result<Option<u32>> {
+        self.inner.keepalive_ms().map(|m| m.map(|m| m / 1000))
+    }
+
+    pub fn set_keepalive_with_interval(&self, idle: u32, interval: u32)
+                                       -> io::Result<()> {
+        try!(self.inner.set_keepalive_ms(Some(idle * 1000)));
+        set_keepalive_interval(self.inner.as_raw_fd(), interval)
+    }
+
+    pub fn set_linger(&self, seconds: Option<u32>) -> io::Result<()> {
+        let val = linger {
+            l_onoff: seconds.is_some() as libc::c_int,
+            l_linger: seconds.unwrap_or(0) as libc::c_int,
+        };
+        super::net::setsockopt(self.inner.as_raw_fd(), libc::SOL_SOCKET,
+                               SO_LINGER, &val)
+    }
+
+    pub fn linger(&self) -> io::Result<Option<u32>> {
+        let val: linger = try!(super::net::getsockopt(self.inner.as_raw_fd(),
+                                                      libc::SOL_SOCKET,
+                                                      SO_LINGER));
+        if val.l_onoff == 0 {
+            Ok(None)
+        } else {
+            Ok(Some(val.l_linger as u32))
+        }
+    }
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+#[allow(non_camel_case_types)]
+struct linger {
+    l_onoff: libc::c_int,
+    l_linger: libc::c_int,
+}
+
+// libc doesn't provide `SO_LINGER`. On OSX and iOS `SO_LINGER` is measured in
+// clock ticks, so use the variant that takes seconds there.
+#[cfg(any(target_os = "linux", target_os = "android"))]
+const SO_LINGER: libc::c_int = 13;
+#[cfg(any(target_os = "macos", target_os = "ios"))]
+const SO_LINGER: libc::c_int = 0x1080;
+#[cfg(not(any(target_os = "linux", target_os = "android",
+              target_os = "macos", target_os = "ios")))]
+const SO_LINGER: libc::c_int = 0x0080;
+
+// libc doesn't provide `TCP_KEEPINTVL`
+#[cfg(any(target_os = "linux", target_os = "android"))]
+const TCP_KEEPINTVL: libc::c_int = 5;
+#[cfg(any(target_os = "macos", target_os = "ios"))]
+const TCP_KEEPINTVL: libc::c_int = 0x101;
+#[cfg(any(target_os = "freebsd", target_os = "dragonfly"))]
+const TCP_KEEPINTVL: libc::c_int = 512;
+
+#[cfg(any(target_os = "linux", target_os = "android", target_os = "macos",
+          target_os = "ios", target_os = "freebsd", target_os = "dragonfly"))]
+fn set_keepalive_interval(fd: RawFd, interval: u32) -> io::Result<()> {
+    super::net::setsockopt(fd, libc::IPPROTO_TCP, TCP_KEEPINTVL,
+                           &(interval as libc::c_int))
+}
+
+#[cfg(not(any(target_os = "linux", target_os = "android", target_os = "macos",
+              target_os = "ios", target_os = "freebsd", target_os = "dragonfly")))]
+fn set_keepalive_interval(_fd: RawFd, _interval: u32) -> io::Result<()> {
+    Err(io::Error::new(io::ErrorKind::Other,
+                       "keepalive interval is not supported on this platform"))
 }
 
 impl Read for TcpStream {
@@ -121,10 +196,10 @@ impl TcpListener {
         self.inner.try_clone().map(|s| TcpListener { inner: s })
     }
 
-    pub fn accept(&self) -> io::Result<Option<TcpStream>> {
-        self.inner.accept().and_then(|(s, _)| {
+    pub fn accept(&self) -> io::Result<Option<(TcpStream, SocketAddr)>> {
+        self.inner.accept().and_then(|(s, a)| {
             try!(set_nonblock(&s));
-            Ok(Some(TcpStream { inner: s }))
+            Ok(Some((TcpStream { inner: s }, a)))
         }).or_else(io::to_non_block)
     }
 }
@@ -1,9 +1,20 @@
 use {io, Evented, EventSet, Io, IpAddr, PollOpt, Selector, Token};
 use bytes::{Buf, MutBuf};
+use libc;
 use sys::unix::{net, nix, Socket};
-use std::net::SocketAddr;
+use std::net::{Ipv4Addr, SocketAddr};
 use std::os::unix::io::{RawFd, AsRawFd, FromRawFd};
 
+#[cfg(any(target_os = "linux", target_os = "android"))]
+const IP_MULTICAST_IF: libc::c_int = 32;
+#[cfg(any(target_os = "linux", target_os = "android"))]
+const IPV6_MULTICAST_IF: libc::c_int = 17;
+
+#[cfg(not(any(target_os = "linux", target_os = "android")))]
+const IP_MULTICAST_IF: libc::c_int = 9;
+#[cfg(not(any(target_os = "linux", target_os = "android")))]
+const IPV6_MULTICAST_IF: libc::c_int = 9;
+
 #[derive(Debug)]
 pub struct UdpSocket {
     io: Io,
@@ -59,6 +70,23 @@ impl UdpSocket {
             .map_err(super::from_nix_error)
     }
 
+    pub fn broadcast(&self) -> io::Result<bool> {
+        nix::getsockopt(self.as_raw_fd(), nix::sockopt::Broadcast)
+            .map_err(super::from_nix_error)
+    }
+
+    pub fn set_multicast_if_v4(&self, interface: &Ipv4Addr) -> io::Result<()> {
+        let addr = libc::in_addr { s_addr: ::net::in_addr(interface) };
+        net::setsockopt(self.as_raw_fd(), libc::IPPROTO_IP, IP_MULTICAST_IF,
+                        &addr)
+    }
+
+    pub fn set_multicast_if_v6(&self, interface: u32) -> io::Result<()> {
+        let index = interface as libc::c_uint;
+        net::setsockopt(self.as_raw_fd(), libc::IPPROTO_IPV6,
+                        IPV6_MULTICAST_IF, &index)
+    }
+
     pub fn set_multicast_loop(&self, on: bool) -> io::Result<()> {
         nix::setsockopt(self.as_raw_fd(), nix::sockopt::IpMulticastLoop, &on)
             .map_err(super::from_nix_error)
@@ -120,6 +148,7 @@ impl UdpSocket {
     }
 }
 
+// nix doesn't provide the multicast interface options, so set them directly
 impl Evented for UdpSocket {
     fn register(&self, selector: &mut Selector, token: Token, interest: EventSet, opts: PollOpt) -> io::Result<()> {
         self.io.register(selector, token, interest, opts)
@@ -74,7 +74,9 @@
 //! called. While that write operation is in progress all future calls to
 //! `write` will return `WouldBlock`. Completion of the write then translates to
 //! a `writable` event. Note that this will probably want to add some layer of
-//! internal buffering in the future.
+//! internal buffering in the future. Similarly, `flush` returns `WouldBlock`
+//! until the scheduled write has completed (or returns its error). This only
+//! means the data was handed to the kernel, not that the peer received it.
 //!
 //! ## Buffer Management
 //!
@@ -137,7 +139,11 @@
 //!   be some level of buffering of writes probably.
 
 use std::io;
+use std::mem;
 use std::net::Ipv4Addr;
+use std::os::windows::prelude::*;
+
+use winapi::{c_char, c_int, SOCKET};
 
 mod awakener;
 #[macro_use]
@@ -166,3 +172,57 @@ fn wouldblock() -> io::Error {
 }
 
 fn ipv4_any() -> Ipv4Addr { Ipv4Addr::new(0, 0, 0, 0) }
+
+// Winsock functions that neither winapi nor wio provide
+mod ffi {
+    use winapi::{c_char, c_int, DWORD, LPDWORD, LPVOID, SOCKET};
+
+    #[link(name = "ws2_32")]
+    extern "system" {
+        pub fn setsockopt(s: SOCKET, level: c_int, name: c_int,
+                          optval: *const c_char, optlen: c_int) -> c_int;
+
+        pub fn getsockopt(s: SOCKET, level: c_int, name: c_int,
+                          optval: *mut c_char, optlen: *mut c_int) -> c_int;
+
+        // The last two arguments are the `LPWSAOVERLAPPED` and completion
+        // routine, which are always null here.
+        pub fn WSAIoctl(s: SOCKET, code: DWORD,
+                        in_buf: LPVOID, in_len: DWORD,
+                        out_buf: LPVOID, out_len: DWORD,
+                        returned: LPDWORD,
+                        overlapped: LPVOID,
+                        routine: LPVOID) -> c_int;
+    }
+}
+
+// For options that net2 doesn't provide
+fn setsockopt<T>(socket: &AsRawSocket, level: c_int, name: c_int,
+                 val: &T) -> io::Result<()> {
+    let ret = unsafe {
+        ffi::setsockopt(socket.as_raw_socket() as SOCKET, level, name,
+                        val as *const T as *const c_char,
+                        mem::size_of::<T>() as c_int)
+    };
+    if ret == 0 {
+        Ok(())
+    } else {
+        Err(io::Error::last_os_error())
+    }
+}
+
+fn getsockopt<T: Copy>(socket: &AsRawSocket, level: c_int,
+                       name: c_int) -> io::Result<T> {
+    unsafe {
+        let mut val: T = mem::zeroed();
+        let mut len = mem::size_of::<T>() as c_int;
+        let ret = ffi::getsockopt(socket.as_raw_socket() as SOCKET, level,
+                                  name, &mut val as *mut T as *mut c_char,
+                                  &mut len);
+        if ret == 0 {
+            Ok(val)
+        } else {
+            Err(io::Error::last_os_error())
+        }
+    }
+}
@@ -1,4 +1,6 @@
 use std::cell::UnsafeCell;
+use std::collections::HashMap;
+use std::collections::hash_map::Entry;
 use std::io;
 use std::mem;
 use std::os::windows::prelude::*;
@@ -93,6 +95,7 @@ impl Selector {
 
         // Clear out the previous list of I/O events and get some more!
         events.events.truncate(0);
+        let cap = events.capacity();
         let inner = self.inner.clone();
         let n = match inner.port.get_many(&mut events.statuses, Some(timeout)) {
             Ok(statuses) => statuses.len(),
@@ -108,25 +111,37 @@ impl Selector {
         // Note that if we see an I/O completion with a null OVERLAPPED pointer
         // then it means it was our awakener, so just generate a readable
         // notification for it and carry on.
-        let dst = &mut events.events;
-        for status in events.statuses[..n].iter_mut() {
-            if status.overlapped() as usize == 0 {
-                dst.push(IoEvent::new(EventSet::readable(),
-                                      Token(status.token())));
-                continue
+        {
+            let dst = &mut events.events;
+            for status in events.statuses[..n].iter_mut() {
+                if status.overlapped() as usize == 0 {
+                    dst.push(IoEvent::new(EventSet::readable(),
+                                          Token(status.token())));
+                    continue
+                }
+
+                let callback = unsafe {
+                    (*(status.overlapped() as *mut Overlapped)).callback
+                };
+                callback(status, dst);
             }
-
-            let callback = unsafe {
-                (*(status.overlapped() as *mut Overlapped)).callback
-            };
-            callback(status, dst);
         }
 
-        // Finally, clear out the list of deferred events and process them all
-        // here.
-        let defers = mem::replace(&mut *inner.defers.lock().unwrap(), Vec::new());
-        for event in defers {
-            dst.push(event);
+        // A read and a write completion for the same socket each generate an
+        // event, so merge them into one event per token like the other
+        // backends do.
+        events.coalesce();
+
+        // Finally, clear out the list of deferred events and process them
+        // here. The capacity of `events` is a hard limit on the number of
+        // events returned, so anything which doesn't fit stays deferred until
+        // the next call.
+        let mut defers = inner.defers.lock().unwrap();
+        let pending = mem::replace(&mut *defers, Vec::new());
+        for event in pending {
+            if !events.push_coalesced(event, cap) {
+                defers.push(event);
+            }
         }
         Ok(())
     }
@@ -211,7 +226,11 @@ impl Registration {
             return Err(other("socket already registered"))
         }
         try!(Registration::validate_opts(opts));
-        try!(selector.inner.port.add_socket(self.token.as_usize(), socket));
+        // The token is the socket's completion key, so it must survive being
+        // stored as a `ULONG_PTR`.
+        debug_assert_eq!(token.as_usize() as ULONG_PTR as usize,
+                         token.as_usize());
+        try!(selector.inner.port.add_socket(token.as_usize(), socket));
         self.associate(selector, token);
         self.interest = set2mask(interest);
         self.opts = opts;
@@ -288,20 +307,26 @@ pub struct Events {
 
     /// Literal events returned by `get` to the upwards `EventLoop`
     events: Vec<IoEvent>,
+
+    /// Index in `events` of the event for each token, used to coalesce.
+    event_map: HashMap<Token, usize>,
 }
 
 impl Events {
     pub fn new() -> Events {
         // Use a nice large space for receiving I/O events (currently the same
-        // as unix's 1024) and then also prepare the output vector to have the
-        // same space.
-        //
-        // Note that it's possible for the output `events` to grow beyond 1024
-        // capacity as it can also include deferred events, but that's certainly
-        // not the end of the world!
+        // as unix's 1024).
+        Events::with_capacity(1024)
+    }
+
+    pub fn with_capacity(cap: usize) -> Events {
+        // Each completion status generates at most one event, and deferred
+        // events are only added while there's room, so the output vector
+        // never needs to grow beyond the number of statuses.
         Events {
-            statuses: vec![CompletionStatus::zero(); 1024].into_boxed_slice(),
-            events: Vec::with_capacity(1024),
+            statuses: vec![CompletionStatus::zero(); cap].into_boxed_slice(),
+            events: Vec::with_capacity(cap),
+            event_map: HashMap::with_capacity(cap),
         }
     }
 
@@ -309,9 +334,59 @@ impl Events {
         self.events.len()
     }
 
+    pub fn capacity(&self) -> usize {
+        self.statuses.len()
+    }
+
+    pub fn reserve(&mut self, additional: usize) {
+        let cap = self.statuses.len() + additional;
+        self.statuses = vec![CompletionStatus::zero(); cap].into_boxed_slice();
+        let len = self.events.len();
+        self.events.reserve(cap - len);
+        self.event_map.reserve(additional);
+    }
+
     pub fn get(&self, idx: usize) -> IoEvent {
         self.events[idx]
     }
+
+    /// Merges the events generated for the same token, in place, so each token
+    /// appears at most once.
+    fn coalesce(&mut self) {
+        self.event_map.clear();
+
+        let mut len = 0;
+        for i in 0..self.events.len() {
+            let event = self.events[i];
+            match self.event_map.entry(event.token) {
+                Entry::Occupied(e) => {
+                    self.events[*e.get()].kind.insert(event.kind);
+                }
+                Entry::Vacant(e) => {
+                    e.insert(len);
+                    self.events[len] = event;
+                    len += 1;
+                }
+            }
+        }
+        self.events.truncate(len);
+    }
+
+    /// Merges `event` into the event already present for its token, or appends
+    /// it if there are fewer than `cap` events. Returns `false` if the event
+    /// didn't fit.
+    fn push_coalesced(&mut self, event: IoEvent, cap: usize) -> bool {
+        if let Some(&idx) = self.event_map.get(&event.token) {
+            self.events[idx].kind.insert(event.kind);
+            return true
+        }
+        if self.events.len() >= cap {
+            return false
+        }
+        self.event_map.insert(event.token, self.events.len());
+        self.events.push(event);
+        true
+    }
 }
 
 macro_rules! overlapped2arc {
@@ -345,3 +420,52 @@ impl Overlapped {
         FromRawArc::from_raw((overlapped as usize - offset) as *mut T)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::net;
+    use std::usize;
+
+    use wio::iocp::CompletionStatus;
+    use wio::net::UdpSocketExt;
+
+    use {Token, PollOpt};
+    use event::{IoEvent, EventSet};
+    use super::{Events, Overlapped, Registration, Selector};
+
+    // Reports the completion key of the finished operation as the event token
+    fn key_done(status: &CompletionStatus, dst: &mut Vec<IoEvent>) {
+        dst.push(IoEvent::new(EventSet::writable(), Token(status.token())));
+    }
+
+    #[test]
+    fn completion_key_round_trip() {
+        let mut selector = Selector::new().unwrap();
+        let socket = net::UdpSocket::bind("127.0.0.1:0").unwrap();
+        let addr = socket.local_addr().unwrap();
+
+        // Associate the socket with a large token, then complete an operation
+        // on it so the key comes back through a real completion.
+        let token = Token(usize::MAX - 1);
+        let mut registration = Registration::new();
+        registration.register_socket(&socket, &mut selector, token,
+                                     EventSet::writable(),
+                                     PollOpt::edge()).unwrap();
+
+        let overlapped = Overlapped::new(key_done);
+        unsafe {
+            socket.send_to_overlapped(b"x", &addr, overlapped.get_mut())
+                  .unwrap();
+        }
+
+        let mut events = Events::new();
+        for _ in 0..10 {
+            selector.select(&mut events, 100).unwrap();
+            if events.len() > 0 {
+                break
+            }
+        }
+        assert_eq!(events.len(), 1);
+        assert_eq!(events.get(0).token, token);
+    }
+}
@@ -14,7 +14,7 @@ use winapi::*;
 use {Evented, EventSet, PollOpt, Selector, Token};
 use event::IoEvent;
 use sys::windows::selector::{Overlapped, Registration};
-use sys::windows::{wouldblock, Family};
+use sys::windows::{ffi, getsockopt, setsockopt, wouldblock, Family};
 use sys::windows::from_raw_arc::FromRawArc;
 
 pub struct TcpStream {
@@ -108,6 +108,10 @@ impl TcpStream {
         Ok(TcpStream::new(socket, Some(*addr)))
     }
 
+    pub fn from_stream(socket: net::TcpStream) -> io::Result<TcpStream> {
+        Ok(TcpStream::new(socket, None))
+    }
+
     pub fn peer_addr(&self) -> io::Result<SocketAddr> {
         self.inner().socket.peer_addr()
     }
@@ -133,6 +137,58 @@ impl TcpStream {
         net2::TcpStreamExt::set_keepalive_ms(&self.inner().socket, dur)
     }
 
+    pub fn keepalive(&self) -> io::Result<Option<u32>> {
+        net2::TcpStreamExt::keepalive_ms(&self.inner().socket)
+            .map(|m| m.map(|m| m / 1000))
+    }
+
+    pub fn set_keepalive_with_interval(&self, idle: u32, interval: u32)
+                                       -> io::Result<()> {
+        let vals = tcp_keepalive {
+            onoff: 1,
+            keepalivetime: (idle * 1000) as c_ulong,
+            keepaliveinterval: (interval * 1000) as c_ulong,
+        };
+        let mut returned: DWORD = 0;
+        let ret = unsafe {
+            ffi::WSAIoctl(self.inner().socket.as_raw_socket() as SOCKET,
+                          SIO_KEEPALIVE_VALS,
+                          &vals as *const _ as LPVOID,
+                          mem::size_of::<tcp_keepalive>() as DWORD,
+                          0 as LPVOID, 0, &mut returned,
+                          0 as LPVOID, 0 as LPVOID)
+        };
+        if ret == 0 {
+            Ok(())
+        } else {
+            Err(io::Error::last_os_error())
+        }
+    }
+
+    pub fn set_linger(&self, seconds: Option<u32>) -> io::Result<()> {
+        // Winsock stores the timeout in a `u_short`
+        let secs = seconds.unwrap_or(0);
+        if secs > ::std::u16::MAX as u32 {
+            return Err(io::Error::new(io::ErrorKind::InvalidInput,
+                                      "linger timeout is too large"))
+        }
+        let val = linger {
+            l_onoff: seconds.is_some() as c_ushort,
+            l_linger: secs as c_ushort,
+        };
+        setsockopt(&self.inner().socket, SOL_SOCKET, SO_LINGER, &val)
+    }
+
+    pub fn linger(&self) -> io::Result<Option<u32>> {
+        let val: linger = try!(getsockopt(&self.inner().socket, SOL_SOCKET,
+                                          SO_LINGER));
+        if val.l_onoff == 0 {
+            Ok(None)
+        } else {
+            Ok(Some(val.l_linger as u32))
+        }
+    }
+
     fn inner(&self) -> MutexGuard<StreamInner> {
         self.imp.inner()
     }
@@ -251,6 +307,29 @@ impl StreamImp {
     }
 }
 
+// neither net2 nor winapi provide `SO_LINGER`
+const SOL_SOCKET: c_int = 0xffff;
+const SO_LINGER: c_int = 0x0080;
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+#[allow(non_camel_case_types)]
+struct linger {
+    l_onoff: c_ushort,
+    l_linger: c_ushort,
+}
+
+// neither net2 nor winapi provide the keepalive ioctl
+const SIO_KEEPALIVE_VALS: DWORD = 0x98000004;
+
+#[repr(C)]
+#[allow(non_camel_case_types)]
+struct tcp_keepalive {
+    onoff: c_ulong,
+    keepalivetime: c_ulong,
+    keepaliveinterval: c_ulong,
+}
+
 fn read_done(status: &CompletionStatus, dst: &mut Vec<IoEvent>) {
     let me2 = StreamImp {
         inner: unsafe { overlapped2arc!(status.overlapped(), StreamIo, read) },
@@ -333,9 +412,13 @@ impl Write for TcpStream {
     fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
         let mut me = self.inner();
         let me = &mut *me;
-        match me.write {
+        match mem::replace(&mut me.write, State::Empty) {
             State::Empty => {}
-            _ => return Err(wouldblock())
+            State::Error(e) => return Err(e),
+            other => {
+                me.write = other;
+                return Err(wouldblock())
+            }
         }
         if me.iocp.port().is_none() {
             return Err(wouldblock())
@@ -346,8 +429,18 @@ impl Write for TcpStream {
         Ok(amt)
     }
 
+    /// Returns `WouldBlock` while a previously scheduled write is still in
+    /// progress, and the error if that write failed.
     fn flush(&mut self) -> io::Result<()> {
-        Ok(())
+        let mut me = self.inner();
+        match mem::replace(&mut me.write, State::Empty) {
+            State::Empty => Ok(()),
+            State::Error(e) => Err(e),
+            other => {
+                me.write = other;
+                Err(wouldblock())
+            }
+        }
     }
 }
 
@@ -435,7 +528,7 @@ impl TcpListener {
         }
     }
 
-    pub fn accept(&self) -> io::Result<Option<TcpStream>> {
+    pub fn accept(&self) -> io::Result<Option<(TcpStream, SocketAddr)>> {
         let mut me = self.inner();
         let ret = match mem::replace(&mut me.accept, State::Empty) {
             State::Empty => return Ok(None),
@@ -443,7 +536,33 @@ impl TcpListener {
                 me.accept = State::Pending(t);
                 return Ok(None)
             }
-            State::Ready(s) => Ok(Some(TcpStream::new(s, None))),
+            State::Ready(s) => {
+                // A socket accepted through `AcceptEx` doesn't inherit the
+                // listener's properties until `SO_UPDATE_ACCEPT_CONTEXT` is
+                // set on it, and until then functions like `getpeername` and
+                // `shutdown` fail. If the update fails the socket isn't usable,
+                // so it is closed here (by dropping it) and the error is
+                // returned instead.
+                //
+                // The peer address comes from the buffer `AcceptEx` filled in,
+                // which stays valid until the next accept is scheduled below.
+                match me.socket.accept_complete(&s) {
+                    Ok(()) => {
+                        let addr = me.accept_buf.parse(&me.socket).ok()
+                                     .and_then(|addrs| addrs.remote());
+                        match addr {
+                            Some(addr) => {
+                                Ok(Some((TcpStream::new(s, None), addr)))
+                            }
+                            None => {
+                                Err(io::Error::new(io::ErrorKind::Other,
+                                                   "failed to parse socket address"))
+                            }
+                        }
+                    }
+                    Err(e) => Err(e),
+                }
+            }
             State::Error(e) => Err(e),
         };
         self.imp.schedule_accept(&mut me);
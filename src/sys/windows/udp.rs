@@ -7,7 +7,7 @@ use std::fmt;
 use std::io::prelude::*;
 use std::io;
 use std::mem;
-use std::net::{self, SocketAddr};
+use std::net::{self, Ipv4Addr, SocketAddr};
 use std::os::windows::prelude::*;
 use std::sync::{Mutex, MutexGuard};
 
@@ -22,7 +22,7 @@ use bytes::{Buf, MutBuf};
 use event::IoEvent;
 use sys::windows::selector::{Overlapped, Registration};
 use sys::windows::from_raw_arc::FromRawArc;
-use sys::windows::{bad_state, wouldblock, Family};
+use sys::windows::{bad_state, setsockopt, wouldblock, Family};
 
 pub struct UdpSocket {
     imp: Imp,
@@ -186,6 +186,24 @@ impl UdpSocket {
         try!(self.inner().socket.socket()).set_broadcast(on)
     }
 
+    pub fn broadcast(&self) -> io::Result<bool> {
+        try!(self.inner().socket.socket()).broadcast()
+    }
+
+    pub fn set_multicast_if_v4(&self, interface: &Ipv4Addr) -> io::Result<()> {
+        let addr = ::net::in_addr(interface);
+        let me = self.inner();
+        let socket = try!(me.socket.socket());
+        setsockopt(socket, IPPROTO_IP, IP_MULTICAST_IF, &addr)
+    }
+
+    pub fn set_multicast_if_v6(&self, interface: u32) -> io::Result<()> {
+        let index = interface as DWORD;
+        let me = self.inner();
+        let socket = try!(me.socket.socket());
+        setsockopt(socket, IPPROTO_IPV6, IPV6_MULTICAST_IF, &index)
+    }
+
     pub fn set_multicast_loop(&self, on: bool) -> io::Result<()> {
         let me = self.inner();
         let socket = try!(me.socket.socket());
@@ -355,6 +373,12 @@ impl Socket {
     }
 }
 
+// neither net2 nor winapi provide the multicast interface options
+const IPPROTO_IP: c_int = 0;
+const IPPROTO_IPV6: c_int = 41;
+const IP_MULTICAST_IF: c_int = 9;
+const IPV6_MULTICAST_IF: c_int = 9;
+
 fn send_done(status: &CompletionStatus, dst: &mut Vec<IoEvent>) {
     trace!("finished a send {}", status.bytes_transferred());
     let me2 = Imp {
@@ -76,6 +76,36 @@ impl<T> Timer<T> {
         nxt - now
     }
 
+    // Number of ms remaining until the soonest pending timeout, or `None` if
+    // there are no pending timeouts
+    pub fn next_timeout_in_ms(&self) -> Option<u64> {
+        let mut soonest: Option<u64> = None;
+
+        // Every entry is linked into the slot for its tick, so walk all of
+        // them looking for the lowest tick.
+        for &head in self.wheel.iter() {
+            let mut curr = head;
+
+            while curr != EMPTY {
+                let links = self.entries[curr].links;
+
+                soonest = match soonest {
+                    Some(tick) if tick <= links.tick => Some(tick),
+                    _ => Some(links.tick),
+                };
+
+                curr = links.next;
+            }
+        }
+
+        soonest.map(|tick| {
+            let at = self.start + tick * self.tick_ms;
+            let now = self.now_ms();
+
+            if at <= now { 0 } else { at - now }
+        })
+    }
+
     /*
      *
      * ===== Initialization =====
@@ -1,6 +1,6 @@
 extern crate mio;
 
-use mio::{EventLoop, Handler, Token, EventSet, PollOpt};
+use mio::{EventLoop, EventLoopConfig, Handler, Token, EventSet, PollOpt};
 use mio::tcp::TcpListener;
 
 struct E;
@@ -37,3 +37,76 @@ fn add_then_drop() {
     drop(l);
     e.run_once(&mut E).unwrap();
 }
+
+#[test]
+fn reserved_token() {
+    use std::io::ErrorKind;
+    use std::usize;
+
+    let mut e = EventLoop::<E>::new().unwrap();
+    let l = TcpListener::bind(&"127.0.0.1:0".parse().unwrap()).unwrap();
+
+    let err = e.register(&l, Token(usize::MAX), EventSet::readable(),
+                         PollOpt::edge()).unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::InvalidInput);
+
+    e.register(&l, Token(1), EventSet::readable(), PollOpt::edge()).unwrap();
+    let err = e.reregister(&l, Token(usize::MAX), EventSet::readable(),
+                           PollOpt::edge()).unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::InvalidInput);
+}
+
+#[test]
+fn large_token() {
+    use std::net;
+    use std::usize;
+
+    struct H { listener: TcpListener, token: Option<Token> }
+
+    impl Handler for H {
+        type Timeout = ();
+        type Message = ();
+
+        fn ready(&mut self, event_loop: &mut EventLoop<Self>, token: Token,
+                 _: EventSet) {
+            self.token = Some(token);
+            assert!(self.listener.accept().unwrap().is_some());
+            event_loop.shutdown();
+        }
+    }
+
+    let mut e = EventLoop::new().unwrap();
+    let l = TcpListener::bind(&"127.0.0.1:0".parse().unwrap()).unwrap();
+    let addr = l.local_addr().unwrap();
+    let token = Token(usize::MAX - 1);
+    e.register(&l, token, EventSet::readable(), PollOpt::edge()).unwrap();
+
+    let _s = net::TcpStream::connect(&addr).unwrap();
+
+    let mut h = H { listener: l, token: None };
+    e.run(&mut h).unwrap();
+    assert_eq!(h.token, Some(token));
+}
+
+#[test]
+fn invalid_config() {
+    use std::io::ErrorKind;
+
+    fn assert_invalid(config: EventLoopConfig, msg: &str) {
+        match EventLoop::<E>::configured(config) {
+            Err(e) => {
+                assert_eq!(e.kind(), ErrorKind::InvalidInput);
+                assert_eq!(e.to_string(), msg);
+            }
+            Ok(..) => panic!("configuration should have been rejected"),
+        }
+    }
+
+    let mut config = EventLoopConfig::new();
+    config.messages_per_tick(0);
+    assert_invalid(config, "messages per tick must be non-zero");
+
+    let mut config = EventLoopConfig::new();
+    config.timer_tick_ms(0);
+    assert_invalid(config, "timer tick must be non-zero");
+}
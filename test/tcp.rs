@@ -2,6 +2,7 @@ extern crate mio;
 extern crate env_logger;
 
 use std::io::prelude::*;
+use std::io::ErrorKind;
 use std::net;
 use std::sync::mpsc::channel;
 use std::thread;
@@ -247,3 +248,308 @@ fn listen_then_close() {
     let mut h = H;
     e.run_once(&mut h).unwrap();
 }
+
+#[test]
+fn accepted_stream_is_usable() {
+    use mio::tcp::Shutdown;
+
+    // On Windows `peer_addr` and `shutdown` only work on an accepted socket
+    // once its accept context has been updated.
+    struct H { listener: TcpListener, peer: net::SocketAddr }
+
+    impl Handler for H {
+        type Timeout = ();
+        type Message = ();
+
+        fn ready(&mut self, event_loop: &mut EventLoop<Self>, token: Token,
+                 events: EventSet) {
+            assert_eq!(token, Token(1));
+            assert!(events.is_readable());
+
+            let s = self.listener.accept().unwrap().unwrap();
+            assert_eq!(s.peer_addr().unwrap(), self.peer);
+            s.shutdown(Shutdown::Write).unwrap();
+            event_loop.shutdown();
+        }
+    }
+
+    let l = TcpListener::bind(&"127.0.0.1:0".parse().unwrap()).unwrap();
+    let addr = l.local_addr().unwrap();
+    let mut client = net::TcpStream::connect(&addr).unwrap();
+    let peer = client.local_addr().unwrap();
+
+    let mut e = EventLoop::new().unwrap();
+    e.register(&l, Token(1), EventSet::readable(), PollOpt::edge()).unwrap();
+
+    let mut h = H { listener: l, peer: peer };
+    e.run(&mut h).unwrap();
+
+    // The write half was shut down, so the client sees EOF
+    let mut buf = [0; 16];
+    assert_eq!(client.read(&mut buf).unwrap(), 0);
+}
+
+#[test]
+fn incoming() {
+    struct H { accepted: Vec<net::SocketAddr>, listener: TcpListener }
+
+    impl Handler for H {
+        type Timeout = ();
+        type Message = ();
+
+        fn ready(&mut self, event_loop: &mut EventLoop<Self>, token: Token,
+                 events: EventSet) {
+            assert_eq!(token, Token(1));
+            assert!(events.is_readable());
+
+            let mut incoming = self.listener.incoming();
+            for res in &mut incoming {
+                let (s, addr) = res.unwrap();
+                assert_eq!(s.peer_addr().unwrap(), addr);
+                self.accepted.push(addr);
+            }
+            assert!(incoming.next().is_none());
+
+            if self.accepted.len() == 3 {
+                event_loop.shutdown();
+            }
+        }
+    }
+
+    let l = TcpListener::bind(&"127.0.0.1:0".parse().unwrap()).unwrap();
+    let addr = l.local_addr().unwrap();
+
+    let clients = (0..3).map(|_| {
+        net::TcpStream::connect(&addr).unwrap()
+    }).collect::<Vec<_>>();
+
+    let mut e = EventLoop::new().unwrap();
+    e.register(&l, Token(1), EventSet::readable(), PollOpt::edge()).unwrap();
+
+    let mut h = H { accepted: Vec::new(), listener: l };
+    e.run(&mut h).unwrap();
+
+    assert_eq!(h.accepted.len(), clients.len());
+    for c in clients.iter() {
+        assert!(h.accepted.contains(&c.local_addr().unwrap()));
+    }
+    assert!(h.listener.incoming().next().is_none());
+}
+
+#[test]
+fn one_event_per_token_per_tick() {
+    struct H { seen: Vec<Token>, readable: bool, writable: bool }
+
+    impl Handler for H {
+        type Timeout = ();
+        type Message = ();
+
+        fn ready(&mut self, event_loop: &mut EventLoop<Self>, token: Token,
+                 events: EventSet) {
+            assert!(!self.seen.contains(&token));
+            self.seen.push(token);
+
+            self.readable |= events.is_readable();
+            self.writable |= events.is_writable();
+            if self.readable && self.writable {
+                event_loop.shutdown();
+            }
+        }
+
+        fn tick(&mut self, _: &mut EventLoop<Self>) {
+            self.seen.clear();
+        }
+    }
+
+    let l = net::TcpListener::bind("127.0.0.1:0").unwrap();
+    let addr = l.local_addr().unwrap();
+    let s = TcpStream::connect(&addr).unwrap();
+    let mut peer = l.accept().unwrap().0;
+    peer.write(b"hello").unwrap();
+
+    let mut e = EventLoop::new().unwrap();
+    e.register(&s, Token(1), EventSet::readable() | EventSet::writable(),
+               PollOpt::edge()).unwrap();
+
+    let mut h = H { seen: Vec::new(), readable: false, writable: false };
+    e.run(&mut h).unwrap();
+}
+
+#[test]
+fn listener_from_std() {
+    struct H { listener: TcpListener }
+
+    impl Handler for H {
+        type Timeout = ();
+        type Message = ();
+
+        fn ready(&mut self, event_loop: &mut EventLoop<Self>, token: Token,
+                 events: EventSet) {
+            assert_eq!(token, Token(1));
+            assert!(events.is_readable());
+            assert!(self.listener.accept().unwrap().is_some());
+            event_loop.shutdown();
+        }
+    }
+
+    let l = net::TcpListener::bind("127.0.0.1:0").unwrap();
+    let addr = l.local_addr().unwrap();
+    let l = TcpListener::from_std(l).unwrap();
+    assert_eq!(l.local_addr().unwrap(), addr);
+
+    let t = thread::spawn(move || {
+        net::TcpStream::connect(&addr).unwrap();
+    });
+
+    let mut e = EventLoop::new().unwrap();
+    e.register(&l, Token(1), EventSet::readable(), PollOpt::edge()).unwrap();
+
+    let mut h = H { listener: l };
+    e.run(&mut h).unwrap();
+    t.join().unwrap();
+}
+
+#[test]
+fn stream_from_std() {
+    struct H { socket: TcpStream }
+
+    impl Handler for H {
+        type Timeout = ();
+        type Message = ();
+
+        fn ready(&mut self, event_loop: &mut EventLoop<Self>, token: Token,
+                 events: EventSet) {
+            assert_eq!(token, Token(1));
+            assert!(events.is_readable());
+            let mut b = [0; 16];
+            if let Some(amt) = self.socket.try_read(&mut b).unwrap() {
+                assert_eq!(&b[..amt], b"hello");
+                event_loop.shutdown();
+            }
+        }
+    }
+
+    let l = net::TcpListener::bind("127.0.0.1:0").unwrap();
+    let addr = l.local_addr().unwrap();
+
+    let t = thread::spawn(move || {
+        let mut s = l.accept().unwrap().0;
+        s.write(b"hello").unwrap();
+    });
+
+    let s = net::TcpStream::connect(&addr).unwrap();
+    let s = TcpStream::from_std(s).unwrap();
+
+    let mut e = EventLoop::new().unwrap();
+    e.register(&s, Token(1), EventSet::readable(), PollOpt::edge()).unwrap();
+
+    let mut h = H { socket: s };
+    e.run(&mut h).unwrap();
+    t.join().unwrap();
+}
+
+#[test]
+fn flush() {
+    struct H { written: bool, socket: TcpStream }
+
+    impl Handler for H {
+        type Timeout = ();
+        type Message = ();
+
+        fn ready(&mut self, event_loop: &mut EventLoop<Self>, token: Token,
+                 events: EventSet) {
+            assert_eq!(token, Token(1));
+            assert!(events.is_writable());
+            if !self.written {
+                assert_eq!(self.socket.try_write(b"hello").unwrap(), Some(5));
+                self.written = true;
+            }
+            match self.socket.flush() {
+                Ok(()) => event_loop.shutdown(),
+                Err(ref e) if e.kind() == ErrorKind::WouldBlock => {}
+                Err(e) => panic!("flush failed: {}", e),
+            }
+        }
+    }
+
+    let l = net::TcpListener::bind("127.0.0.1:0").unwrap();
+    let addr = l.local_addr().unwrap();
+
+    let t = thread::spawn(move || {
+        let mut s = l.accept().unwrap().0;
+        let mut b = [0; 5];
+        let mut amt = 0;
+        while amt < b.len() {
+            amt += s.read(&mut b[amt..]).unwrap();
+        }
+        assert_eq!(&b, b"hello");
+    });
+
+    let mut e = EventLoop::new().unwrap();
+    let s = TcpStream::connect(&addr).unwrap();
+
+    e.register(&s, Token(1), EventSet::writable(), PollOpt::edge()).unwrap();
+
+    let mut h = H { written: false, socket: s };
+    e.run(&mut h).unwrap();
+    assert!(h.written);
+    t.join().unwrap();
+}
+
+#[test]
+fn set_keepalive() {
+    let l = net::TcpListener::bind("127.0.0.1:0").unwrap();
+    let s = TcpStream::connect(&l.local_addr().unwrap()).unwrap();
+
+    s.set_keepalive(Some(30)).unwrap();
+    assert_eq!(s.keepalive().unwrap(), Some(30));
+    s.set_keepalive(None).unwrap();
+    assert_eq!(s.keepalive().unwrap(), None);
+}
+
+#[test]
+fn set_keepalive_with_interval() {
+    let l = net::TcpListener::bind("127.0.0.1:0").unwrap();
+    let s = TcpStream::connect(&l.local_addr().unwrap()).unwrap();
+
+    s.set_keepalive_with_interval(60, 5).unwrap();
+
+    // Windows reports the probe interval rather than the idle time
+    if cfg!(windows) {
+        assert_eq!(s.keepalive().unwrap(), Some(5));
+    } else {
+        assert_eq!(s.keepalive().unwrap(), Some(60));
+    }
+}
+
+#[test]
+fn set_linger() {
+    let l = net::TcpListener::bind("127.0.0.1:0").unwrap();
+    let s = TcpStream::connect(&l.local_addr().unwrap()).unwrap();
+
+    s.set_linger(Some(5)).unwrap();
+    assert_eq!(s.linger().unwrap(), Some(5));
+    s.set_linger(None).unwrap();
+    assert_eq!(s.linger().unwrap(), None);
+}
+
+#[test]
+fn zero_linger_resets() {
+    let l = net::TcpListener::bind("127.0.0.1:0").unwrap();
+    let addr = l.local_addr().unwrap();
+
+    let (tx, rx) = channel();
+    let t = thread::spawn(move || {
+        let mut s = l.accept().unwrap().0;
+        tx.send(()).unwrap();
+        let mut b = [0; 16];
+        let err = s.read(&mut b).unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::ConnectionReset);
+    });
+
+    let s = TcpStream::from_std(net::TcpStream::connect(&addr).unwrap()).unwrap();
+    rx.recv().unwrap();
+    s.set_linger(Some(0)).unwrap();
+    drop(s);
+    t.join().unwrap();
+}
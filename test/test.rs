@@ -11,8 +11,11 @@ pub use ports::localhost;
 mod test_battery;
 mod test_close_on_drop;
 mod test_echo_server;
+mod test_idle;
 mod test_multicast;
 mod test_notify;
+mod test_poll_capacity;
+mod test_poll_hook;
 mod test_register_deregister;
 #[cfg(unix)]
 mod test_tick;
@@ -0,0 +1,35 @@
+use mio::*;
+
+struct IdleHandler {
+    idle: usize,
+}
+
+impl Handler for IdleHandler {
+    type Timeout = ();
+    type Message = ();
+
+    fn idle(&mut self, _event_loop: &mut EventLoop<IdleHandler>) {
+        self.idle += 1;
+    }
+}
+
+#[test]
+pub fn test_idle_only_when_nothing_happened() {
+    let mut config = EventLoopConfig::new();
+    config.io_poll_timeout_ms(10);
+
+    let mut event_loop = EventLoop::configured(config).unwrap();
+    let mut handler = IdleHandler { idle: 0 };
+
+    // Nothing is registered, so the poll times out and the tick is idle
+    event_loop.run_once(&mut handler).unwrap();
+    assert_eq!(handler.idle, 1);
+
+    // A pending message means the tick did some work
+    event_loop.channel().send(()).unwrap();
+    event_loop.run_once(&mut handler).unwrap();
+    assert_eq!(handler.idle, 1);
+
+    event_loop.run_once(&mut handler).unwrap();
+    assert_eq!(handler.idle, 2);
+}
@@ -0,0 +1,38 @@
+use mio::*;
+use mio::tcp::TcpListener;
+use std::net;
+
+#[test]
+pub fn test_poll_respects_capacity() {
+    let mut poll = Poll::with_capacity(1).unwrap();
+    assert_eq!(poll.capacity(), 1);
+
+    let l1 = TcpListener::bind(&"127.0.0.1:0".parse().unwrap()).unwrap();
+    let l2 = TcpListener::bind(&"127.0.0.1:0".parse().unwrap()).unwrap();
+    poll.register(&l1, Token(1), EventSet::readable(), PollOpt::edge()).unwrap();
+    poll.register(&l2, Token(2), EventSet::readable(), PollOpt::edge()).unwrap();
+
+    let _s1 = net::TcpStream::connect(&l1.local_addr().unwrap()).unwrap();
+    let _s2 = net::TcpStream::connect(&l2.local_addr().unwrap()).unwrap();
+
+    // Both listeners are ready, but only one event fits per call
+    let mut tokens = vec![];
+    while tokens.len() < 2 {
+        assert_eq!(poll.poll(1_000).unwrap(), 1);
+        tokens.push(poll.event(0).token);
+    }
+    tokens.sort();
+    assert_eq!(tokens, [Token(1), Token(2)]);
+}
+
+#[test]
+pub fn test_poll_reserve() {
+    let mut poll = Poll::with_capacity(1).unwrap();
+    poll.reserve(15);
+    assert!(poll.capacity() >= 16);
+}
+
+#[test]
+pub fn test_poll_zero_capacity() {
+    assert!(Poll::with_capacity(0).is_err());
+}
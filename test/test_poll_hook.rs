@@ -0,0 +1,26 @@
+use mio::*;
+use mio::tcp::TcpListener;
+use std::net;
+use std::sync::{Arc, Mutex};
+
+#[test]
+pub fn test_poll_hook() {
+    let phases = Arc::new(Mutex::new(Vec::new()));
+    let mut poll = Poll::new().unwrap();
+
+    let l = TcpListener::bind(&"127.0.0.1:0".parse().unwrap()).unwrap();
+    poll.register(&l, Token(0), EventSet::readable(), PollOpt::edge()).unwrap();
+
+    let recorded = phases.clone();
+    poll.set_poll_hook(Box::new(move |phase| {
+        recorded.lock().unwrap().push(phase);
+    }));
+
+    let _s = net::TcpStream::connect(&l.local_addr().unwrap()).unwrap();
+    assert_eq!(poll.poll(1_000).unwrap(), 1);
+
+    let phases = phases.lock().unwrap();
+    assert_eq!(phases.len(), 2);
+    assert_eq!(phases[0], PollPhase::Enter { timeout_ms: 1_000 });
+    assert_eq!(phases[1], PollPhase::Exit { events: 1 });
+}
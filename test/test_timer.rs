@@ -149,3 +149,38 @@ pub fn test_timer() {
 
     assert!(handler.state == AfterHup, "actual={:?}", handler.state);
 }
+
+struct NextTimeoutHandler;
+
+impl Handler for NextTimeoutHandler {
+    type Timeout = ();
+    type Message = ();
+}
+
+#[test]
+pub fn test_next_timeout() {
+    let mut config = EventLoopConfig::new();
+    config.timer_tick_ms(1_000);
+
+    let mut event_loop = EventLoop::<NextTimeoutHandler>::configured(config).unwrap();
+    assert_eq!(event_loop.next_timeout_ms(), None);
+
+    // Timeouts fire on the tick at or after their deadline, and the loop may
+    // already be part way into the current tick.
+    let far = event_loop.timeout_ms((), 5_000).unwrap();
+    let first = event_loop.next_timeout_ms().unwrap();
+    assert!(first > 4_000 && first <= 6_000, "{}", first);
+
+    let near = event_loop.timeout_ms((), 2_000).unwrap();
+    let first = event_loop.next_timeout_ms().unwrap();
+    assert!(first > 1_000 && first <= 3_000, "{}", first);
+
+    super::sleep_ms(50);
+    let second = event_loop.next_timeout_ms().unwrap();
+    assert!(second < first, "{} >= {}", second, first);
+
+    event_loop.clear_timeout(near);
+    assert!(event_loop.next_timeout_ms().unwrap() > 3_000);
+    event_loop.clear_timeout(far);
+    assert_eq!(event_loop.next_timeout_ms(), None);
+}
@@ -80,3 +80,71 @@ pub fn test_udp_socket() {
     info!("Starting event loop to test with...");
     event_loop.run(&mut UdpHandler::new(tx, rx, "hello world")).unwrap();
 }
+
+#[test]
+pub fn test_udp_socket_options() {
+    use std::net::Ipv4Addr;
+
+    let any = str::FromStr::from_str("0.0.0.0:0").unwrap();
+    let sock = UdpSocket::bound(&any).unwrap();
+
+    sock.set_broadcast(true).unwrap();
+    assert!(sock.broadcast().unwrap());
+    sock.set_broadcast(false).unwrap();
+    assert!(!sock.broadcast().unwrap());
+
+    sock.set_multicast_if_v4(&Ipv4Addr::new(127, 0, 0, 1)).unwrap();
+
+    // Not every host has IPv6, so only check the option where a socket can be
+    // bound. Interface index 0 selects the system default interface.
+    let any6 = str::FromStr::from_str("[::]:0").unwrap();
+    if let Ok(sock6) = UdpSocket::bound(&any6) {
+        sock6.set_multicast_if_v6(0).unwrap();
+    }
+}
+
+// Linux routes 127.255.255.255 as the loopback broadcast address, so this is
+// where the environment reliably permits sending a broadcast.
+#[cfg(target_os = "linux")]
+#[test]
+pub fn test_udp_broadcast() {
+    use std::net::SocketAddr;
+
+    struct H { rx: UdpSocket, rx_buf: RingBuf }
+
+    impl Handler for H {
+        type Timeout = ();
+        type Message = ();
+
+        fn ready(&mut self, event_loop: &mut EventLoop<H>, token: Token,
+                 events: EventSet) {
+            assert_eq!(token, LISTENER);
+            assert!(events.is_readable());
+            assert!(self.rx.recv_from(&mut self.rx_buf).unwrap().is_some());
+            assert_eq!(self.rx_buf.bytes(), &b"hello broadcast"[..]);
+            event_loop.shutdown();
+        }
+    }
+
+    let any = str::FromStr::from_str("0.0.0.0:0").unwrap();
+    let rx = UdpSocket::bound(&any).unwrap();
+    let port = rx.local_addr().unwrap().port();
+
+    let tx = UdpSocket::bound(&localhost()).unwrap();
+    let target: SocketAddr = format!("127.255.255.255:{}", port).parse().unwrap();
+
+    // Without `SO_BROADCAST` the kernel refuses to send to the address
+    let mut buf = SliceBuf::wrap(b"hello broadcast");
+    assert!(tx.send_to(&mut buf, &target).is_err());
+
+    tx.set_broadcast(true).unwrap();
+    assert!(tx.broadcast().unwrap());
+
+    let mut event_loop = EventLoop::new().unwrap();
+    event_loop.register(&rx, LISTENER, EventSet::readable(), PollOpt::edge()).unwrap();
+
+    let mut buf = SliceBuf::wrap(b"hello broadcast");
+    assert!(tx.send_to(&mut buf, &target).unwrap().is_some());
+
+    event_loop.run(&mut H { rx: rx, rx_buf: RingBuf::new(1024) }).unwrap();
+}